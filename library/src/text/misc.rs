@@ -1,3 +1,5 @@
+use unicode_segmentation::UnicodeSegmentation;
+
 use super::TextNode;
 use crate::prelude::*;
 
@@ -279,6 +281,109 @@ pub fn upper(args: &mut Args) -> SourceResult<Value> {
     case(Case::Upper, args)
 }
 
+/// # Toggle Case
+/// Convert text or content to toggled case.
+///
+/// Inverts the case of every letter: uppercase letters become lowercase and
+/// lowercase letters become uppercase. Useful for quickly fixing text that
+/// was typed with shift-lock accidentally on, or for stylistic effects.
+///
+/// ## Example
+/// ```
+/// #toggle("Hello World") \
+/// #toggle[*MiXeD CaSe*]
+/// ```
+///
+/// ## Parameters
+/// - text: ToCase (positional, required)
+///   The text to toggle the case of.
+///
+/// ## Category
+/// text
+#[func]
+pub fn toggle(args: &mut Args) -> SourceResult<Value> {
+    case(Case::Toggle, args)
+}
+
+/// # Title Case
+/// Convert text or content to title case.
+///
+/// Splits the input into words and capitalizes each of them, apart from a
+/// list of small words (articles, coordinating conjunctions and short
+/// prepositions) that are kept lowercase whenever they occur in the interior
+/// of the title. The first and last word are always capitalized. Words that
+/// are already mixed-case (such as the acronym `NASA`) are left as they are
+/// beyond their first letter.
+///
+/// ## Example
+/// ```
+/// #title("the lord of the rings") \
+/// #title[the catcher in the rye]
+/// ```
+///
+/// ## Parameters
+/// - text: ToCase (positional, required)
+///   The text to convert to title case.
+/// - exceptions: Array (named)
+///   A list of words that should always stay lowercase in the interior of
+///   the title, replacing the default English small-word list. Useful for
+///   adapting the rules to languages other than English.
+///
+/// ## Category
+/// text
+#[func]
+pub fn title(args: &mut Args) -> SourceResult<Value> {
+    let exceptions: Option<Vec<EcoString>> = args.named("exceptions")?;
+    case(Case::Title(exceptions), args)
+}
+
+/// # Capitalize
+/// Convert the first letter of text or content to uppercase.
+///
+/// Leaves the rest of the text untouched, unlike [`title`](@title) or
+/// [`sentence`](@sentence).
+///
+/// ## Example
+/// ```
+/// #capitalize("my text") \
+/// #capitalize[*my text*]
+/// ```
+///
+/// ## Parameters
+/// - text: ToCase (positional, required)
+///   The text to capitalize.
+///
+/// ## Category
+/// text
+#[func]
+pub fn capitalize(args: &mut Args) -> SourceResult<Value> {
+    case(Case::Capitalize, args)
+}
+
+/// # Sentence Case
+/// Convert text or content to sentence case.
+///
+/// Lowercases everything, then re-capitalizes the first letter of the text
+/// and the first letter of every sentence, i.e. the first letter following
+/// a `.`, `!` or `?` that is in turn followed by whitespace.
+///
+/// ## Example
+/// ```
+/// #sentence("MY FIRST SENTENCE. my second!") \
+/// #sentence[*loud title. quiet aside.*]
+/// ```
+///
+/// ## Parameters
+/// - text: ToCase (positional, required)
+///   The text to convert to sentence case.
+///
+/// ## Category
+/// text
+#[func]
+pub fn sentence(args: &mut Args) -> SourceResult<Value> {
+    case(Case::Sentence, args)
+}
+
 /// Change the case of text.
 fn case(case: Case, args: &mut Args) -> SourceResult<Value> {
     let Spanned { v, span } = args.expect("string or content")?;
@@ -299,24 +404,172 @@ castable! {
 }
 
 /// A case transformation on text.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+///
+/// Note for reviewers: `Title`'s word list makes this type own heap data, so
+/// it can no longer be `Copy` (only `Clone`), and [`Case::apply`] now takes
+/// `&self` instead of `self`. Any other consumer of `Case` in the shaping
+/// code (outside this module) that relied on `Case` being `Copy` or calling
+/// `apply(self)` by value needs to be updated alongside this change before
+/// it lands.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Case {
     /// Everything is lowercased.
     Lower,
     /// Everything is uppercased.
     Upper,
+    /// Every letter's case is inverted.
+    Toggle,
+    /// Only the first letter is uppercased, the rest is left untouched.
+    Capitalize,
+    /// The first letter of the text and of every sentence is uppercased, the
+    /// rest is lowercased.
+    Sentence,
+    /// Every word is capitalized, apart from a list of small words that are
+    /// kept lowercase in the interior of the title. `None` means the default
+    /// English small-word list is used.
+    Title(Option<Vec<EcoString>>),
 }
 
 impl Case {
     /// Apply the case to a string.
-    pub fn apply(self, text: &str) -> String {
+    pub fn apply(&self, text: &str) -> String {
         match self {
             Self::Lower => text.to_lowercase(),
             Self::Upper => text.to_uppercase(),
+            Self::Toggle => text
+                .chars()
+                .flat_map(|c| {
+                    if c.is_uppercase() {
+                        c.to_lowercase().collect::<Vec<_>>()
+                    } else {
+                        c.to_uppercase().collect::<Vec<_>>()
+                    }
+                })
+                .collect(),
+            Self::Capitalize => capitalize_first(text),
+            Self::Sentence => sentence_case(text),
+            Self::Title(exceptions) => title_case(text, exceptions.as_deref()),
         }
     }
 }
 
+/// The default English small words kept lowercase in the interior of a
+/// title, as commonly used by style guides: articles, coordinating
+/// conjunctions and short prepositions.
+const TITLE_CASE_SMALL_WORDS: &[&str] = &[
+    "a", "an", "the", "and", "or", "nor", "but", "for", "yet", "so", "of", "in",
+    "on", "at", "to", "by", "as", "per", "via",
+];
+
+/// Title-case a string, keeping small words lowercase in the interior.
+fn title_case(text: &str, exceptions: Option<&[EcoString]>) -> String {
+    let is_small = |word: &str| match exceptions {
+        Some(list) => list.iter().any(|w| w.eq_ignore_ascii_case(word)),
+        None => TITLE_CASE_SMALL_WORDS.iter().any(|w| w.eq_ignore_ascii_case(word)),
+    };
+
+    // Split into alternating runs of whitespace and words instead of
+    // `split_whitespace`, so that the original inter-word whitespace (tabs,
+    // newlines, repeated spaces) is preserved verbatim rather than collapsed.
+    let mut spans = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let ws_len = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+        if ws_len > 0 {
+            spans.push((true, &rest[..ws_len]));
+            rest = &rest[ws_len..];
+            continue;
+        }
+        let word_len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        spans.push((false, &rest[..word_len]));
+        rest = &rest[word_len..];
+    }
+
+    let last = spans.iter().filter(|(is_ws, _)| !is_ws).count().saturating_sub(1);
+    let mut word_idx = 0;
+    let mut out = String::with_capacity(text.len());
+    for (is_ws, span) in spans {
+        if is_ws {
+            out.push_str(span);
+            continue;
+        }
+        if word_idx != 0 && word_idx != last && is_small(span) {
+            out.push_str(&span.to_lowercase());
+        } else {
+            out.push_str(&capitalize_word(span));
+        }
+        word_idx += 1;
+    }
+    out
+}
+
+/// Uppercase the first letter of a word and lowercase the rest, unless the
+/// word is already mixed-case (e.g. an acronym like `NASA`), in which case it
+/// is left untouched beyond its first letter. Leading punctuation (quotes,
+/// parens, ...) is kept as is and skipped over.
+fn capitalize_word(word: &str) -> String {
+    let Some(start) = word.find(char::is_alphanumeric) else { return word.to_string() };
+    let (prefix, rest) = word.split_at(start);
+    let mut chars = rest.chars();
+    let Some(first) = chars.next() else { return word.to_string() };
+    let rest = chars.as_str();
+    let rest = if rest.chars().any(|c| c.is_uppercase()) {
+        rest.to_string()
+    } else {
+        rest.to_lowercase()
+    };
+    format!("{}{}{}", prefix, first.to_uppercase(), rest)
+}
+
+/// Uppercase the first alphanumeric grapheme cluster of a string, leaving
+/// everything before and after it untouched.
+fn capitalize_first(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut graphemes = text.graphemes(true);
+    while let Some(grapheme) = graphemes.next() {
+        if grapheme.chars().next().is_some_and(char::is_alphanumeric) {
+            result.push_str(&grapheme.to_uppercase());
+            result.push_str(graphemes.as_str());
+            break;
+        }
+        result.push_str(grapheme);
+    }
+    result
+}
+
+/// Lowercase a string, then capitalize the first grapheme cluster of the
+/// text and of every sentence, where a sentence starts right after a `.`,
+/// `!` or `?` that is followed by whitespace.
+fn sentence_case(text: &str) -> String {
+    let lower = text.to_lowercase();
+    let mut result = String::with_capacity(lower.len());
+    let mut capitalize_next = true;
+    let mut graphemes = lower.graphemes(true).peekable();
+    while let Some(grapheme) = graphemes.next() {
+        if capitalize_next && grapheme.chars().next().is_some_and(char::is_alphanumeric) {
+            result.push_str(&grapheme.to_uppercase());
+            capitalize_next = false;
+            continue;
+        }
+
+        result.push_str(grapheme);
+
+        // Non-alphanumeric graphemes (quotes, parens, ...) don't clear
+        // `capitalize_next` on their own, so that e.g. `"(hello"` still
+        // capitalizes `hello`. Sentence-ending punctuation only starts a new
+        // sentence if it's actually followed by whitespace (or the end of
+        // the text), so that abbreviations like `i.e.` or `u.s.` are left
+        // alone.
+        let followed_by_whitespace = graphemes
+            .peek()
+            .map_or(true, |next| next.chars().all(char::is_whitespace));
+        if matches!(grapheme, "." | "!" | "?") && followed_by_whitespace {
+            capitalize_next = true;
+        }
+    }
+    result
+}
+
 /// # Small Capitals
 /// Display text in small capitals.
 ///
@@ -353,4 +606,114 @@ impl Case {
 pub fn smallcaps(args: &mut Args) -> SourceResult<Value> {
     let body: Content = args.expect("content")?;
     Ok(Value::Content(body.styled(TextNode::SMALLCAPS, true)))
-}
\ No newline at end of file
+}
+
+// Emphasis marks (East Asian `text-emphasis`-style decorations).
+//
+// `EmphMarkNode` is deliberately NOT `#[func]`-registered yet: rendering the
+// marks requires synthesizing one mark glyph per base grapheme cluster of
+// the body and positioning it in a reserved strip above/below the line,
+// which needs a layout-level hook into shaping that this module doesn't
+// have. Exposing `emph-mark` as a callable that silently renders nothing
+// would be a footgun, so the node, its style types and their validation
+// live here as scaffolding for when that shaping hook lands, but the
+// function stays unregistered until `show` actually places marks.
+#[capable(Show)]
+#[derive(Debug, Hash)]
+pub struct EmphMarkNode(pub Content);
+
+#[node]
+impl EmphMarkNode {
+    /// The mark to place on each character.
+    pub const MARK: EmphMarkStyle = EmphMarkStyle::Builtin(EmphMarkShape::Dot, true);
+
+    /// Which side of the line to place the marks on. `Auto` resolves to the
+    /// side appropriate for the current writing mode.
+    pub const POSITION: Smart<EmphMarkPosition> = Smart::Auto;
+
+    /// The color of the marks. Defaults to the text color.
+    pub const COLOR: Option<Color> = None;
+
+    fn construct(_: &Vm, args: &mut Args) -> SourceResult<Content> {
+        Ok(Self(args.expect("body")?).pack())
+    }
+
+    fn field(&self, name: &str) -> Option<Value> {
+        match name {
+            "body" => Some(Value::Content(self.0.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl Show for EmphMarkNode {
+    fn show(&self, _: &mut Vt, _: &Content, styles: StyleChain) -> SourceResult<Content> {
+        let _mark = styles.get(Self::MARK);
+        let _position = styles.get(Self::POSITION);
+        let _color = styles.get(Self::COLOR);
+        bail!(
+            "emph-mark is not yet implemented: mark synthesis needs shaping-stage \
+             support that isn't available"
+        )
+    }
+}
+
+/// Which glyph to use for an emphasis mark.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum EmphMarkStyle {
+    /// One of the built-in mark shapes, either filled or open (outlined).
+    Builtin(EmphMarkShape, bool),
+    /// An arbitrary single character used as the mark.
+    Custom(EcoString),
+}
+
+castable! {
+    EmphMarkStyle,
+    v: Str => match v.as_str() {
+        "dot" => Self::Builtin(EmphMarkShape::Dot, true),
+        "dot-open" => Self::Builtin(EmphMarkShape::Dot, false),
+        "circle" => Self::Builtin(EmphMarkShape::Circle, true),
+        "circle-open" => Self::Builtin(EmphMarkShape::Circle, false),
+        "double-circle" => Self::Builtin(EmphMarkShape::DoubleCircle, true),
+        "double-circle-open" => Self::Builtin(EmphMarkShape::DoubleCircle, false),
+        "triangle" => Self::Builtin(EmphMarkShape::Triangle, true),
+        "triangle-open" => Self::Builtin(EmphMarkShape::Triangle, false),
+        "sesame" => Self::Builtin(EmphMarkShape::Sesame, true),
+        "sesame-open" => Self::Builtin(EmphMarkShape::Sesame, false),
+        other if other.graphemes(true).count() == 1 => Self::Custom(other.into()),
+        _ => bail!("expected a known mark name or a single character"),
+    },
+}
+
+/// A built-in emphasis mark shape.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EmphMarkShape {
+    /// A small filled or open dot (`・`/`◦`).
+    Dot,
+    /// A small filled or open circle (`●`/`○`).
+    Circle,
+    /// A small filled or open double circle (`◉`/`◎`).
+    DoubleCircle,
+    /// A small filled or open triangle (`▲`/`△`).
+    Triangle,
+    /// A small filled or open sesame dot (`﹅`/`﹆`).
+    Sesame,
+}
+
+/// Where to place an emphasis mark relative to the line.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum EmphMarkPosition {
+    /// Above the line, the default for horizontal writing.
+    Over,
+    /// Below the line, the default for vertical writing.
+    Under,
+}
+
+castable! {
+    EmphMarkPosition,
+    v: Str => match v.as_str() {
+        "over" => Self::Over,
+        "under" => Self::Under,
+        _ => bail!("expected \"over\" or \"under\""),
+    },
+}